@@ -1,32 +1,67 @@
 use std::{error::Error, io, time::Duration};
 
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use crossterm::event::{self, Event, KeyCode};
 use ratatui::{self, prelude::*, widgets::*};
 
+mod panic_hook;
+mod tui;
+
+use panic_hook::install_panic_hook;
+
+const MIN_SPLIT: u16 = 10;
+const MAX_SPLIT: u16 = 90;
+const SPLIT_STEP: u16 = 5;
+
+struct App {
+    focused: usize,
+    horizontal_split: u16,
+    left_vertical_split: u16,
+    right_vertical_split: u16,
+}
+
+impl App {
+    fn new() -> App {
+        App {
+            focused: 0,
+            horizontal_split: 30,
+            left_vertical_split: 50,
+            right_vertical_split: 80,
+        }
+    }
+
+    fn focus_next(&mut self) {
+        self.focused = (self.focused + 1) % 4;
+    }
+
+    fn focus_previous(&mut self) {
+        self.focused = (self.focused + 3) % 4;
+    }
+
+    fn adjust_horizontal_split(&mut self, delta: i16) {
+        self.horizontal_split = clamp_split(self.horizontal_split, delta);
+    }
+
+    fn adjust_vertical_split(&mut self, delta: i16) {
+        let split = if self.focused < 2 {
+            &mut self.left_vertical_split
+        } else {
+            &mut self.right_vertical_split
+        };
+        *split = clamp_split(*split, delta);
+    }
+}
+
+fn clamp_split(split: u16, delta: i16) -> u16 {
+    let split = split as i16 + delta;
+    split.clamp(MIN_SPLIT as i16, MAX_SPLIT as i16) as u16
+}
+
 pub fn main() -> Result<(), Box<dyn Error>> {
-    // setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    // create app and run it
-    let res = run_app(&mut terminal);
+    install_panic_hook();
 
-    // restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.clear()?;
-    terminal.show_cursor()?;
+    let mut terminal = tui::init()?;
+    let res = run_app(&mut terminal);
+    tui::restore()?;
 
     if let Err(err) = res {
         println!("{err:?}");
@@ -36,49 +71,81 @@ pub fn main() -> Result<(), Box<dyn Error>> {
 }
 
 fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
+    let mut app = App::new();
+
     loop {
-        terminal.draw(ui)?;
+        terminal.draw(|f| ui(f, &app))?;
 
         if event::poll(Duration::from_millis(250))? {
             if let Event::Key(key) = event::read()? {
-                if let KeyCode::Char('q') = key.code {
-                    return Ok(());
+                match key.code {
+                    KeyCode::Char('q') => return Ok(()),
+                    KeyCode::Tab => app.focus_next(),
+                    KeyCode::BackTab => app.focus_previous(),
+                    KeyCode::Char('h') => app.adjust_horizontal_split(-(SPLIT_STEP as i16)),
+                    KeyCode::Char('l') => app.adjust_horizontal_split(SPLIT_STEP as i16),
+                    KeyCode::Char('k') => app.adjust_vertical_split(SPLIT_STEP as i16),
+                    KeyCode::Char('j') => app.adjust_vertical_split(-(SPLIT_STEP as i16)),
+                    _ => {}
                 }
             }
         }
     }
 }
 
-fn ui<B: Backend>(f: &mut Frame<B>) {
+fn panel_block(title: &'static str, color: Color, focused: bool) -> Block<'static> {
+    let block = Block::default()
+        .style(Style::default().bg(color))
+        .title(ratatui::widgets::block::Title::from(title))
+        .borders(Borders::ALL);
+    if focused {
+        block
+            .border_type(BorderType::Thick)
+            .border_style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+    } else {
+        block.border_style(Style::default().fg(Color::DarkGray))
+    }
+}
+
+fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
     let size = f.size();
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .constraints([
+            Constraint::Percentage(app.horizontal_split),
+            Constraint::Percentage(100 - app.horizontal_split),
+        ])
         .split(size);
 
     let sub_chunks_left = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Percentage(app.left_vertical_split),
+            Constraint::Percentage(100 - app.left_vertical_split),
+        ])
         .split(chunks[0]);
     let sub_chunks_right = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
+        .constraints([
+            Constraint::Percentage(app.right_vertical_split),
+            Constraint::Percentage(100 - app.right_vertical_split),
+        ])
         .split(chunks[1]);
 
-    let first_block = Block::default()
-        .style(Style::default().bg(Color::Green))
-        .title(ratatui::widgets::block::Title::from("1 Title"));
-    f.render_widget(first_block, sub_chunks_left[0]);
-    let second_block = Block::default()
-        .style(Style::default().bg(Color::Yellow))
-        .title(ratatui::widgets::block::Title::from("2 Title"));
-    f.render_widget(second_block, sub_chunks_left[1]);
-    let third_block = Block::default()
-        .style(Style::default().bg(Color::Blue))
-        .title(ratatui::widgets::block::Title::from("3 Title"));
-    f.render_widget(third_block, sub_chunks_right[0]);
-    let fourth_block = Block::default()
-        .style(Style::default().bg(Color::Red))
-        .title(ratatui::widgets::block::Title::from("4 Title"));
-    f.render_widget(fourth_block, sub_chunks_right[1]);
+    f.render_widget(
+        panel_block("1 Title", Color::Green, app.focused == 0),
+        sub_chunks_left[0],
+    );
+    f.render_widget(
+        panel_block("2 Title", Color::Yellow, app.focused == 1),
+        sub_chunks_left[1],
+    );
+    f.render_widget(
+        panel_block("3 Title", Color::Blue, app.focused == 2),
+        sub_chunks_right[0],
+    );
+    f.render_widget(
+        panel_block("4 Title", Color::Red, app.focused == 3),
+        sub_chunks_right[1],
+    );
 }
@@ -1,32 +1,45 @@
 use std::{error::Error, io, time::Duration};
 
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use crossterm::event::{self, Event, KeyCode};
 use ratatui::{self, prelude::*, widgets::*};
 
+mod panic_hook;
+mod tui;
+
+use panic_hook::install_panic_hook;
+
+struct App {
+    content: String,
+    scroll_offset: u16,
+    max_scroll: u16,
+    viewport_height: u16,
+}
+
+impl App {
+    fn new() -> App {
+        App {
+            content: "Hello World\nSome text\nEven more text".to_string(),
+            scroll_offset: 0,
+            max_scroll: 0,
+            viewport_height: 0,
+        }
+    }
+
+    fn scroll_up(&mut self, amount: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(amount);
+    }
+
+    fn scroll_down(&mut self, amount: u16) {
+        self.scroll_offset = (self.scroll_offset + amount).min(self.max_scroll);
+    }
+}
+
 pub fn main() -> Result<(), Box<dyn Error>> {
-    // setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    // create app and run it
-    let res = run_app(&mut terminal);
+    install_panic_hook();
 
-    // restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.clear()?;
-    terminal.show_cursor()?;
+    let mut terminal = tui::init()?;
+    let res = run_app(&mut terminal);
+    tui::restore()?;
 
     if let Err(err) = res {
         println!("{err:?}");
@@ -36,20 +49,53 @@ pub fn main() -> Result<(), Box<dyn Error>> {
 }
 
 fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
+    let mut app = App::new();
+
     loop {
-        terminal.draw(ui)?;
+        terminal.draw(|f| ui(f, &mut app))?;
 
         if event::poll(Duration::from_millis(250))? {
             if let Event::Key(key) = event::read()? {
-                if let KeyCode::Char('q') = key.code {
-                    return Ok(());
+                match key.code {
+                    KeyCode::Char('q') => return Ok(()),
+                    KeyCode::Up => app.scroll_up(1),
+                    KeyCode::Down => app.scroll_down(1),
+                    KeyCode::PageUp => app.scroll_up(app.viewport_height),
+                    KeyCode::PageDown => app.scroll_down(app.viewport_height),
+                    _ => {}
                 }
             }
         }
     }
 }
 
-fn ui<B: Backend>(f: &mut Frame<B>) {
+/// Counts how many terminal rows `text` occupies once word-wrapped to `width`
+/// columns, mirroring ratatui's `Wrap { trim: false }` behaviour closely
+/// enough to drive scroll clamping.
+fn wrapped_line_count(text: &str, width: u16) -> usize {
+    let width = width.max(1) as usize;
+    text.lines()
+        .map(|line| {
+            let mut rows = 0usize;
+            let mut current_len = 0usize;
+            for word in line.split_whitespace() {
+                let word_len = word.chars().count();
+                if current_len == 0 {
+                    rows += 1;
+                    current_len = word_len;
+                } else if current_len + 1 + word_len <= width {
+                    current_len += 1 + word_len;
+                } else {
+                    rows += 1;
+                    current_len = word_len;
+                }
+            }
+            rows.max(1)
+        })
+        .sum()
+}
+
+fn ui<B: Backend>(f: &mut Frame<B>, app: &mut App) {
     let size = f.size();
     let block = Block::default().title("Border").borders(Borders::ALL);
     f.render_widget(block, size);
@@ -60,7 +106,22 @@ fn ui<B: Backend>(f: &mut Frame<B>) {
         .constraints([Constraint::Min(1), Constraint::Length(2)])
         .split(size);
 
-    let message_top = Paragraph::new("Hello World\nSome text\nEven more text")
-        .block(Block::default().borders(Borders::NONE));
-    f.render_widget(message_top, chunks[0]);
+    let message_area = chunks[0];
+    let total_lines = wrapped_line_count(&app.content, message_area.width);
+    app.viewport_height = message_area.height;
+    app.max_scroll = (total_lines as u16).saturating_sub(message_area.height);
+    app.scroll_offset = app.scroll_offset.min(app.max_scroll);
+
+    let message_top = Paragraph::new(app.content.as_str())
+        .block(Block::default().borders(Borders::NONE))
+        .wrap(Wrap { trim: false })
+        .scroll((app.scroll_offset, 0));
+    f.render_widget(message_top, message_area);
+
+    let mut scrollbar_state =
+        ScrollbarState::new(total_lines).position(app.scroll_offset as usize);
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None);
+    f.render_stateful_widget(scrollbar, message_area, &mut scrollbar_state);
 }
@@ -0,0 +1,172 @@
+use std::{error::Error, io, time::Duration};
+
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{self, prelude::*, widgets::*};
+
+mod panic_hook;
+mod tui;
+
+use panic_hook::install_panic_hook;
+
+struct Message {
+    author: String,
+    body: String,
+}
+
+struct Room {
+    name: String,
+    messages: Vec<Message>,
+}
+
+struct App {
+    rooms: Vec<Room>,
+    room_list_state: ListState,
+    current_room: usize,
+    input: String,
+}
+
+impl App {
+    fn new() -> App {
+        let rooms = vec![
+            Room {
+                name: "general".to_string(),
+                messages: vec![
+                    Message {
+                        author: "alice".to_string(),
+                        body: "hey, anyone around?".to_string(),
+                    },
+                    Message {
+                        author: "bob".to_string(),
+                        body: "yep, just pushed the fix".to_string(),
+                    },
+                ],
+            },
+            Room {
+                name: "random".to_string(),
+                messages: vec![Message {
+                    author: "carol".to_string(),
+                    body: "anyone seen the new ratatui release?".to_string(),
+                }],
+            },
+        ];
+
+        let mut room_list_state = ListState::default();
+        room_list_state.select(Some(0));
+
+        App {
+            rooms,
+            room_list_state,
+            current_room: 0,
+            input: String::new(),
+        }
+    }
+
+    fn select_previous_room(&mut self) {
+        if self.current_room == 0 {
+            return;
+        }
+        self.current_room -= 1;
+        self.room_list_state.select(Some(self.current_room));
+    }
+
+    fn select_next_room(&mut self) {
+        if self.current_room + 1 >= self.rooms.len() {
+            return;
+        }
+        self.current_room += 1;
+        self.room_list_state.select(Some(self.current_room));
+    }
+
+    fn submit_message(&mut self) {
+        if self.input.is_empty() {
+            return;
+        }
+        let body = std::mem::take(&mut self.input);
+        self.rooms[self.current_room].messages.push(Message {
+            author: "me".to_string(),
+            body,
+        });
+    }
+}
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    install_panic_hook();
+
+    let mut terminal = tui::init()?;
+    let res = run_app(&mut terminal);
+    tui::restore()?;
+
+    if let Err(err) = res {
+        println!("{err:?}");
+    }
+
+    Ok(())
+}
+
+fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
+    let mut app = App::new();
+
+    loop {
+        terminal.draw(|f| ui(f, &mut app))?;
+
+        if event::poll(Duration::from_millis(250))? {
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    KeyCode::Esc => return Ok(()),
+                    KeyCode::Enter => app.submit_message(),
+                    KeyCode::Up => app.select_previous_room(),
+                    KeyCode::Down => app.select_next_room(),
+                    KeyCode::Backspace => {
+                        app.input.pop();
+                    }
+                    KeyCode::Char(c) => app.input.push(c),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn ui<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+    let size = f.size();
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+        .split(size);
+
+    let rooms: Vec<ListItem> = app
+        .rooms
+        .iter()
+        .map(|room| ListItem::new(room.name.as_str()))
+        .collect();
+    let rooms_list = List::new(rooms)
+        .block(Block::default().title("Rooms").borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+        .highlight_symbol("> ");
+    f.render_stateful_widget(rooms_list, chunks[0], &mut app.room_list_state);
+
+    let main_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(3)])
+        .split(chunks[1]);
+
+    let messages: Vec<ListItem> = app.rooms[app.current_room]
+        .messages
+        .iter()
+        .map(|message| ListItem::new(format!("{}: {}", message.author, message.body)))
+        .collect();
+    let messages_list = List::new(messages).block(
+        Block::default()
+            .title(app.rooms[app.current_room].name.as_str())
+            .borders(Borders::ALL),
+    );
+    f.render_widget(messages_list, main_chunks[0]);
+
+    let input = Paragraph::new(app.input.as_str())
+        .block(Block::default().title("Message").borders(Borders::ALL));
+    f.render_widget(input, main_chunks[1]);
+    f.set_cursor(
+        main_chunks[1].x + app.input.len() as u16 + 1,
+        main_chunks[1].y + 1,
+    );
+}
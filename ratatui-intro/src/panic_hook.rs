@@ -0,0 +1,19 @@
+use std::io::stdout;
+
+use crossterm::{
+    event::DisableMouseCapture,
+    execute,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+
+/// Installs a panic hook that restores the terminal before handing off to the
+/// previously installed hook, so a panic inside `ui`/widget code doesn't leave
+/// the shell stuck in raw mode and the alternate screen.
+pub fn install_panic_hook() {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(stdout(), LeaveAlternateScreen, DisableMouseCapture);
+        original_hook(panic_info);
+    }));
+}
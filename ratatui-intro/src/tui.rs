@@ -0,0 +1,28 @@
+use std::io::{self, Stdout};
+
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{backend::CrosstermBackend, Terminal};
+
+/// A type alias for the terminal type used by the examples.
+pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// Sets up the terminal for a ratatui application: enables raw mode and
+/// switches to the alternate screen with mouse capture enabled.
+pub fn init() -> io::Result<DefaultTerminal> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    Terminal::new(backend)
+}
+
+/// Restores the terminal to its original state.
+pub fn restore() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    Ok(())
+}